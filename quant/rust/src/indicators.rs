@@ -0,0 +1,253 @@
+//! Streaming technical indicators built on the internal [`RingBuffer`],
+//! so strategies and backtests don't reimplement SMA/EMA/RSI bookkeeping
+//! per-strategy.
+
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::RingBuffer;
+
+fn require_positive_period(period: usize) -> PyResult<()> {
+    if period == 0 {
+        return Err(PyValueError::new_err("period must be greater than zero"));
+    }
+    Ok(())
+}
+
+/// Simple moving average over the last `period` prices, maintained with
+/// the same incremental-sum technique as `RingBufferF64`'s rolling
+/// stats: `sum += price - evicted` instead of rescanning the window.
+#[pyclass(module = "kapital_rust", name = "RollingSMA")]
+pub struct RollingSMA {
+    period: usize,
+    window: RingBuffer<f64>,
+    sum: f64,
+}
+
+#[pymethods]
+impl RollingSMA {
+    #[new]
+    pub fn new(period: usize) -> PyResult<Self> {
+        require_positive_period(period)?;
+        Ok(Self {
+            period,
+            window: RingBuffer::new(period)?,
+            sum: 0.0,
+        })
+    }
+
+    /// Feeds one price and returns the current SMA, or `NaN` until
+    /// `period` prices have accumulated.
+    pub fn push(&mut self, price: f64) -> f64 {
+        let evicted = if self.window.is_full() {
+            self.window.front()
+        } else {
+            None
+        };
+        self.window.push(price);
+        match evicted {
+            Some(y) => self.sum += price - y,
+            None => self.sum += price,
+        }
+        if self.is_ready() {
+            self.sum / self.period as f64
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Feeds a slice of prices and returns the per-step SMA outputs as a
+    /// NumPy array, for backtests that want the whole run at once.
+    #[allow(deprecated)]
+    pub fn extend(&mut self, py: Python<'_>, prices: Vec<f64>) -> Py<PyArray1<f64>> {
+        let out: Vec<f64> = prices.into_iter().map(|p| self.push(p)).collect();
+        PyArray1::from_vec(py, out).to_owned()
+    }
+
+    #[getter]
+    pub fn is_ready(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    /// Clears all accumulated state, as if freshly constructed.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
+
+/// Exponential moving average with `alpha = 2 / (period + 1)`, seeded by
+/// the SMA of the first `period` samples.
+#[pyclass(module = "kapital_rust", name = "RollingEMA")]
+pub struct RollingEMA {
+    period: usize,
+    alpha: f64,
+    seed_sum: f64,
+    seed_count: usize,
+    ema: Option<f64>,
+}
+
+#[pymethods]
+impl RollingEMA {
+    #[new]
+    pub fn new(period: usize) -> PyResult<Self> {
+        require_positive_period(period)?;
+        Ok(Self {
+            period,
+            alpha: 2.0 / (period as f64 + 1.0),
+            seed_sum: 0.0,
+            seed_count: 0,
+            ema: None,
+        })
+    }
+
+    /// Feeds one price and returns the current EMA, or `NaN` while still
+    /// accumulating the seed SMA.
+    pub fn push(&mut self, price: f64) -> f64 {
+        match self.ema {
+            Some(prev) => {
+                let next = self.alpha * price + (1.0 - self.alpha) * prev;
+                self.ema = Some(next);
+                next
+            }
+            None => {
+                self.seed_sum += price;
+                self.seed_count += 1;
+                if self.seed_count == self.period {
+                    let seeded = self.seed_sum / self.period as f64;
+                    self.ema = Some(seeded);
+                    seeded
+                } else {
+                    f64::NAN
+                }
+            }
+        }
+    }
+
+    /// Feeds a slice of prices and returns the per-step EMA outputs as a
+    /// NumPy array, for backtests that want the whole run at once.
+    #[allow(deprecated)]
+    pub fn extend(&mut self, py: Python<'_>, prices: Vec<f64>) -> Py<PyArray1<f64>> {
+        let out: Vec<f64> = prices.into_iter().map(|p| self.push(p)).collect();
+        PyArray1::from_vec(py, out).to_owned()
+    }
+
+    #[getter]
+    pub fn is_ready(&self) -> bool {
+        self.ema.is_some()
+    }
+
+    /// Clears all accumulated state, as if freshly constructed.
+    pub fn reset(&mut self) {
+        self.seed_sum = 0.0;
+        self.seed_count = 0;
+        self.ema = None;
+    }
+}
+
+/// Wilder-smoothed RSI: `avg_gain`/`avg_loss` are seeded from the first
+/// `period` price deltas, then updated as
+/// `avg = (avg * (period - 1) + latest) / period`.
+#[pyclass(module = "kapital_rust", name = "RollingRSI")]
+pub struct RollingRSI {
+    period: usize,
+    prev_price: Option<f64>,
+    seed_gain_sum: f64,
+    seed_loss_sum: f64,
+    seed_count: usize,
+    avg_gain: Option<f64>,
+    avg_loss: Option<f64>,
+}
+
+#[pymethods]
+impl RollingRSI {
+    #[new]
+    pub fn new(period: usize) -> PyResult<Self> {
+        require_positive_period(period)?;
+        Ok(Self {
+            period,
+            prev_price: None,
+            seed_gain_sum: 0.0,
+            seed_loss_sum: 0.0,
+            seed_count: 0,
+            avg_gain: None,
+            avg_loss: None,
+        })
+    }
+
+    /// Feeds one price and returns the current RSI, or `NaN` until
+    /// `period` price deltas have accumulated.
+    pub fn push(&mut self, price: f64) -> f64 {
+        let prev = match self.prev_price.replace(price) {
+            Some(prev) => prev,
+            None => return f64::NAN,
+        };
+        let delta = price - prev;
+        let gain = delta.max(0.0);
+        let loss = (-delta).max(0.0);
+
+        match (self.avg_gain, self.avg_loss) {
+            (Some(ag), Some(al)) => {
+                let period = self.period as f64;
+                let next_gain = (ag * (period - 1.0) + gain) / period;
+                let next_loss = (al * (period - 1.0) + loss) / period;
+                self.avg_gain = Some(next_gain);
+                self.avg_loss = Some(next_loss);
+                Self::rsi_from(next_gain, next_loss)
+            }
+            _ => {
+                self.seed_gain_sum += gain;
+                self.seed_loss_sum += loss;
+                self.seed_count += 1;
+                if self.seed_count == self.period {
+                    let period = self.period as f64;
+                    let ag = self.seed_gain_sum / period;
+                    let al = self.seed_loss_sum / period;
+                    self.avg_gain = Some(ag);
+                    self.avg_loss = Some(al);
+                    Self::rsi_from(ag, al)
+                } else {
+                    f64::NAN
+                }
+            }
+        }
+    }
+
+    /// Feeds a slice of prices and returns the per-step RSI outputs as a
+    /// NumPy array, for backtests that want the whole run at once.
+    #[allow(deprecated)]
+    pub fn extend(&mut self, py: Python<'_>, prices: Vec<f64>) -> Py<PyArray1<f64>> {
+        let out: Vec<f64> = prices.into_iter().map(|p| self.push(p)).collect();
+        PyArray1::from_vec(py, out).to_owned()
+    }
+
+    #[getter]
+    pub fn is_ready(&self) -> bool {
+        self.avg_gain.is_some()
+    }
+
+    /// Clears all accumulated state, as if freshly constructed.
+    pub fn reset(&mut self) {
+        self.prev_price = None;
+        self.seed_gain_sum = 0.0;
+        self.seed_loss_sum = 0.0;
+        self.seed_count = 0;
+        self.avg_gain = None;
+        self.avg_loss = None;
+    }
+}
+
+impl RollingRSI {
+    fn rsi_from(avg_gain: f64, avg_loss: f64) -> f64 {
+        if avg_loss == 0.0 {
+            if avg_gain == 0.0 {
+                50.0
+            } else {
+                100.0
+            }
+        } else {
+            100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+        }
+    }
+}
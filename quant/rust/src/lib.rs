@@ -1,24 +1,51 @@
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::ptr;
+
 use numpy::PyArray1;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyBufferError, PyIndexError, PyValueError};
+use pyo3::ffi;
 use pyo3::prelude::*;
+use pyo3::types::PyMemoryView;
+
+mod indicators;
+use indicators::{RollingEMA, RollingRSI, RollingSMA};
+
+/// Selects how a `RingBuffer` maps a logical cursor to a physical slot.
+///
+/// `Modulo` supports any capacity. `Mask` requires a power-of-two capacity
+/// but replaces the `% capacity` in the hot push/snapshot paths with a
+/// single `& mask`, which matters in tick-ingestion loops that push on
+/// every tick.
+#[derive(Clone, Copy, Debug)]
+enum Indexing {
+    Modulo,
+    Mask(usize),
+}
 
 /// Internal generic ring buffer used by the exposed PyO3 classes.
+///
+/// `head` is the physical slot of the oldest (logical index 0) element
+/// whenever `len > 0`; `push` (once full) and `pop_front` move it forward
+/// and `push_front` moves it back, so the deque surface stays O(1).
 #[derive(Clone, Debug)]
-struct RingBuffer<T>
+pub(crate) struct RingBuffer<T>
 where
     T: Copy + Default,
 {
     data: Vec<T>,
     capacity: usize,
     len: usize,
-    cursor: usize,
+    head: usize,
+    indexing: Indexing,
 }
 
 impl<T> RingBuffer<T>
 where
     T: Copy + Default,
 {
-    fn new(capacity: usize) -> Result<Self, PyErr> {
+    pub(crate) fn new(capacity: usize) -> Result<Self, PyErr> {
         if capacity == 0 {
             return Err(PyValueError::new_err("capacity must be greater than zero"));
         }
@@ -26,18 +53,105 @@ where
             data: vec![T::default(); capacity],
             capacity,
             len: 0,
-            cursor: 0,
+            head: 0,
+            indexing: Indexing::Modulo,
         })
     }
 
-    fn push(&mut self, value: T) {
-        self.data[self.cursor] = value;
-        self.cursor += 1;
-        if self.cursor == self.capacity {
-            self.cursor = 0;
+    /// Builds a buffer whose capacity is rounded up to the next power of
+    /// two so `push`/`snapshot_vec` can index with `& mask` instead of
+    /// `% capacity`. The effective (rounded) capacity is what `capacity()`
+    /// reports afterwards.
+    fn new_pow2(min_capacity: usize) -> Result<Self, PyErr> {
+        if min_capacity == 0 {
+            return Err(PyValueError::new_err("capacity must be greater than zero"));
+        }
+        let capacity = min_capacity.next_power_of_two();
+        Ok(Self {
+            data: vec![T::default(); capacity],
+            capacity,
+            len: 0,
+            head: 0,
+            indexing: Indexing::Mask(capacity - 1),
+        })
+    }
+
+    /// Advances a physical slot forward by `steps`, wrapping at `capacity`.
+    fn advance(&self, slot: usize, steps: usize) -> usize {
+        match self.indexing {
+            Indexing::Mask(mask) => slot.wrapping_add(steps) & mask,
+            Indexing::Modulo => (slot + steps) % self.capacity,
+        }
+    }
+
+    /// Steps a physical slot one position backward, wrapping at `capacity`.
+    fn retreat_one(&self, slot: usize) -> usize {
+        match self.indexing {
+            Indexing::Mask(mask) => slot.wrapping_sub(1) & mask,
+            Indexing::Modulo => {
+                if slot == 0 {
+                    self.capacity - 1
+                } else {
+                    slot - 1
+                }
+            }
         }
+    }
+
+    /// Push a value onto the back, overwriting the oldest element (and
+    /// advancing `head`) once the buffer is full.
+    pub(crate) fn push(&mut self, value: T) {
+        let tail = self.advance(self.head, self.len);
+        self.data[tail] = value;
         if self.len < self.capacity {
             self.len += 1;
+        } else {
+            self.head = self.advance(self.head, 1);
+        }
+    }
+
+    /// Push a value onto the front, dropping the newest element if full.
+    fn push_front(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.len -= 1;
+        }
+        self.head = self.retreat_one(self.head);
+        self.data[self.head] = value;
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.data[self.head];
+        self.head = self.advance(self.head, 1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let position = self.physical(self.len - 1);
+        self.len -= 1;
+        Some(self.data[position])
+    }
+
+    pub(crate) fn front(&self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.data[self.head])
+        }
+    }
+
+    fn back(&self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.data[self.physical(self.len - 1)])
         }
     }
 
@@ -47,40 +161,82 @@ where
         }
     }
 
-    fn clear(&mut self) {
+    pub(crate) fn clear(&mut self) {
         self.len = 0;
-        self.cursor = 0;
+        self.head = 0;
     }
 
-    fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         self.len
     }
 
-    fn is_full(&self) -> bool {
+    pub(crate) fn is_full(&self) -> bool {
         self.len == self.capacity
     }
 
+    /// Maps a logical index (0 = oldest) to its physical slot in `data`.
+    fn physical(&self, logical: usize) -> usize {
+        self.advance(self.head, logical)
+    }
+
     fn snapshot_vec(&self) -> Vec<T> {
         let mut out = Vec::with_capacity(self.len);
-        if self.len == 0 {
-            return out;
-        }
-        let start = if self.len == self.capacity {
-            self.cursor
-        } else {
-            0
-        };
         for idx in 0..self.len {
-            let position = (start + idx) % self.capacity;
-            out.push(self.data[position]);
+            out.push(self.data[self.physical(idx)]);
         }
         out
     }
+
+    /// Resolves a Python-style index (negative counts back from the
+    /// newest element) to a logical index in `0..len`, or `PyIndexError`.
+    fn resolve_index(&self, idx: isize) -> Result<usize, PyErr> {
+        let len = self.len as isize;
+        let logical = if idx < 0 { idx + len } else { idx };
+        if logical < 0 || logical >= len {
+            return Err(PyIndexError::new_err("ring buffer index out of range"));
+        }
+        Ok(logical as usize)
+    }
+
+    fn get(&self, idx: isize) -> Result<T, PyErr> {
+        let logical = self.resolve_index(idx)?;
+        Ok(self.data[self.physical(logical)])
+    }
+
+    fn set(&mut self, idx: isize, value: T) -> Result<(), PyErr> {
+        let logical = self.resolve_index(idx)?;
+        let position = self.physical(logical);
+        self.data[position] = value;
+        Ok(())
+    }
+
+    /// Returns a logical sub-range `[start, stop)` as a fresh `Vec`,
+    /// clamped to the buffer's bounds the way Python slicing clamps.
+    /// Negative bounds count back from the newest element.
+    fn slice_vec(&self, start: isize, stop: isize) -> Vec<T> {
+        let len = self.len as isize;
+        let clamp = |i: isize| -> isize {
+            let i = if i < 0 { i + len } else { i };
+            i.clamp(0, len)
+        };
+        let start = clamp(start);
+        let stop = clamp(stop);
+        if start >= stop {
+            return Vec::new();
+        }
+        (start..stop)
+            .map(|idx| self.data[self.physical(idx as usize)])
+            .collect()
+    }
 }
 
 #[pyclass(module = "kapital_rust", name = "RingBufferI64")]
 pub struct RingBufferI64 {
     inner: RingBuffer<i64>,
+    // See the matching fields on `RingBufferF64` for why `__getbuffer__`
+    // needs storage with a stable address instead of a local.
+    buffer_shape: [isize; 1],
+    buffer_strides: [isize; 1],
 }
 
 #[pymethods]
@@ -89,6 +245,8 @@ impl RingBufferI64 {
     pub fn new(capacity: usize) -> PyResult<Self> {
         Ok(Self {
             inner: RingBuffer::new(capacity)?,
+            buffer_shape: [0],
+            buffer_strides: [0],
         })
     }
 
@@ -100,6 +258,32 @@ impl RingBufferI64 {
         self.inner.extend_from_slice(&values);
     }
 
+    /// Inserts `value` at the front, dropping the newest element if the
+    /// buffer is already full.
+    pub fn push_front(&mut self, value: i64) {
+        self.inner.push_front(value);
+    }
+
+    /// Removes and returns the oldest element, or `None` if empty.
+    pub fn pop_front(&mut self) -> Option<i64> {
+        self.inner.pop_front()
+    }
+
+    /// Removes and returns the newest element, or `None` if empty.
+    pub fn pop_back(&mut self) -> Option<i64> {
+        self.inner.pop_back()
+    }
+
+    /// Peeks at the oldest element without removing it.
+    pub fn front(&self) -> Option<i64> {
+        self.inner.front()
+    }
+
+    /// Peeks at the newest element without removing it.
+    pub fn back(&self) -> Option<i64> {
+        self.inner.back()
+    }
+
     pub fn clear(&mut self) {
         self.inner.clear();
     }
@@ -127,16 +311,263 @@ impl RingBufferI64 {
         self.inner.snapshot_vec()
     }
 
+    /// Gets the element at logical index `idx` in O(1). Index 0 is the
+    /// oldest element; negative indices count back from the newest
+    /// (`-1` is the newest), Python-style.
+    pub fn get(&self, idx: isize) -> PyResult<i64> {
+        self.inner.get(idx)
+    }
+
+    #[pyo3(name = "__getitem__")]
+    fn py_getitem(&self, idx: isize) -> PyResult<i64> {
+        self.inner.get(idx)
+    }
+
+    #[pyo3(name = "__setitem__")]
+    fn py_setitem(&mut self, idx: isize, value: i64) -> PyResult<()> {
+        self.inner.set(idx, value)
+    }
+
+    /// Returns the logical sub-range `[start, stop)` as a `Vec<i64>`
+    /// without copying the rest of the buffer.
+    pub fn slice(&self, start: isize, stop: isize) -> Vec<i64> {
+        self.inner.slice_vec(start, stop)
+    }
+
     #[allow(deprecated)]
     pub fn to_numpy(&self, py: Python<'_>) -> PyResult<Py<PyArray1<i64>>> {
         let snapshot = self.inner.snapshot_vec();
         Ok(PyArray1::from_vec(py, snapshot).to_owned())
     }
+
+    /// Exposes the raw physical storage through Python's buffer protocol
+    /// (`__getbuffer__` below) so NumPy can wrap it without copying, e.g.
+    /// `np.asarray(buf, copy=False)` or `memoryview(buf)`. This is the
+    /// fastest view, but it is NOT in logical (oldest-to-newest) order
+    /// once the buffer has wrapped — use `ordered_view()` for that, or
+    /// `snapshot()`/`to_numpy()` for an owned, always-ordered copy. The
+    /// view aliases `data` and is invalidated by the next `push`/
+    /// `extend`/`push_front`/`pop_front`/`pop_back`: do not hold it
+    /// across a mutating call.
+    pub fn view<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyMemoryView>> {
+        PyMemoryView::from(slf.as_any())
+    }
+
+    /// Like `view()` but in logical order: borrows the same memory as
+    /// `view()` when the buffer hasn't wrapped yet AND is full (`head ==
+    /// 0` and `len == capacity`, so the physical layout already matches
+    /// logical order with no trailing unused slots), and falls back to a
+    /// single owned, ordered copy otherwise, since one linear buffer view
+    /// can't express a wraparound or exclude the not-yet-written tail of
+    /// a partially filled buffer. Same invalidation contract as `view()`
+    /// when it returns a borrowed view.
+    #[allow(deprecated)]
+    pub fn ordered_view(slf: &Bound<'_, Self>) -> PyResult<PyObject> {
+        let unwrapped_and_full = {
+            let borrowed = slf.borrow();
+            borrowed.inner.head == 0 && borrowed.inner.is_full()
+        };
+        if unwrapped_and_full {
+            Ok(Self::view(slf)?.into_any().unbind())
+        } else {
+            let snapshot = slf.borrow().inner.snapshot_vec();
+            let array = PyArray1::from_vec(slf.py(), snapshot).to_owned();
+            Ok(array.into_any())
+        }
+    }
+
+    unsafe fn __getbuffer__(
+        mut slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("view is null"));
+        }
+
+        let len = slf.inner.data.len();
+        slf.buffer_shape = [len as isize];
+        slf.buffer_strides = [std::mem::size_of::<i64>() as isize];
+        let data_ptr = slf.inner.data.as_mut_ptr();
+
+        (*view).obj = ffi::_Py_NewRef(slf.as_ptr());
+        (*view).buf = data_ptr as *mut std::os::raw::c_void;
+        (*view).len = (len * std::mem::size_of::<i64>()) as isize;
+        // RingBufferI64 never carries rolling statistics (that's an
+        // F64-only feature), so there's nothing an in-place write here
+        // could desync; always writable, matching the other mutators.
+        (*view).readonly = 0;
+        (*view).itemsize = std::mem::size_of::<i64>() as isize;
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            CString::new("q").unwrap().into_raw()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).ndim = 1;
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            slf.buffer_shape.as_mut_ptr()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            slf.buffer_strides.as_mut_ptr()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = ptr::null_mut();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(_slf: PyRefMut<'_, Self>, view: *mut ffi::Py_buffer) {
+        if !(*view).format.is_null() {
+            drop(CString::from_raw((*view).format));
+        }
+    }
+}
+
+/// Incremental window statistics for `RingBufferF64`, opted into via
+/// `with_stats` so the plain push/pop path pays nothing for buffers that
+/// don't need it.
+///
+/// `sum`/`sum_sq` are updated in O(1) per push from the evicted value
+/// rather than rescanned from a snapshot; `refresh` resets them exactly
+/// every `refresh_every` evictions to bound accumulated float error.
+/// `min`/`max` are served from a monotonic deque of `(sequence, value)`
+/// pairs so the window extreme is always at the front after expiring
+/// indices that fell out of the window.
+#[derive(Clone, Debug)]
+struct RollingStats {
+    sum: f64,
+    sum_sq: f64,
+    refresh_every: usize,
+    evictions_since_refresh: usize,
+    next_seq: u64,
+    min_deque: VecDeque<(u64, f64)>,
+    max_deque: VecDeque<(u64, f64)>,
+}
+
+impl RollingStats {
+    fn new(refresh_every: usize) -> Self {
+        Self {
+            sum: 0.0,
+            sum_sq: 0.0,
+            refresh_every,
+            evictions_since_refresh: 0,
+            next_seq: 0,
+            min_deque: VecDeque::new(),
+            max_deque: VecDeque::new(),
+        }
+    }
+
+    /// Folds in a newly pushed value, given the value it evicted (if the
+    /// buffer was already full) and the buffer's capacity/length after
+    /// the push.
+    fn record_push(&mut self, value: f64, evicted: Option<f64>, capacity: usize, len_after: usize) {
+        match evicted {
+            Some(y) => {
+                self.sum += value - y;
+                self.sum_sq += value * value - y * y;
+                self.evictions_since_refresh += 1;
+            }
+            None => {
+                self.sum += value;
+                self.sum_sq += value * value;
+            }
+        }
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        while matches!(self.max_deque.back(), Some(&(_, v)) if v <= value) {
+            self.max_deque.pop_back();
+        }
+        self.max_deque.push_back((seq, value));
+        while matches!(self.min_deque.back(), Some(&(_, v)) if v >= value) {
+            self.min_deque.pop_back();
+        }
+        self.min_deque.push_back((seq, value));
+
+        let valid_from = if len_after == capacity {
+            seq + 1 - capacity as u64
+        } else {
+            0
+        };
+        while matches!(self.max_deque.front(), Some(&(s, _)) if s < valid_from) {
+            self.max_deque.pop_front();
+        }
+        while matches!(self.min_deque.front(), Some(&(s, _)) if s < valid_from) {
+            self.min_deque.pop_front();
+        }
+    }
+
+    fn needs_refresh(&self) -> bool {
+        self.refresh_every > 0 && self.evictions_since_refresh >= self.refresh_every
+    }
+
+    /// Recomputes `sum`/`sum_sq`/the min-max deques exactly from a
+    /// snapshot of the current window, discarding accumulated float error.
+    fn refresh(&mut self, snapshot: &[f64]) {
+        self.sum = snapshot.iter().sum();
+        self.sum_sq = snapshot.iter().map(|v| v * v).sum();
+        self.evictions_since_refresh = 0;
+
+        self.min_deque.clear();
+        self.max_deque.clear();
+        let start_seq = self.next_seq - snapshot.len() as u64;
+        for (idx, &value) in snapshot.iter().enumerate() {
+            let seq = start_seq + idx as u64;
+            while matches!(self.max_deque.back(), Some(&(_, v)) if v <= value) {
+                self.max_deque.pop_back();
+            }
+            self.max_deque.push_back((seq, value));
+            while matches!(self.min_deque.back(), Some(&(_, v)) if v >= value) {
+                self.min_deque.pop_back();
+            }
+            self.min_deque.push_back((seq, value));
+        }
+    }
+
+    fn mean(&self, len: usize) -> f64 {
+        if len == 0 {
+            f64::NAN
+        } else {
+            self.sum / len as f64
+        }
+    }
+
+    fn variance(&self, len: usize) -> f64 {
+        if len == 0 {
+            0.0
+        } else {
+            let mean = self.mean(len);
+            (self.sum_sq / len as f64 - mean * mean).max(0.0)
+        }
+    }
+
+    fn std(&self, len: usize) -> f64 {
+        self.variance(len).sqrt()
+    }
+
+    fn min(&self) -> Option<f64> {
+        self.min_deque.front().map(|&(_, v)| v)
+    }
+
+    fn max(&self) -> Option<f64> {
+        self.max_deque.front().map(|&(_, v)| v)
+    }
 }
 
 #[pyclass(module = "kapital_rust", name = "RingBufferF64")]
 pub struct RingBufferF64 {
     inner: RingBuffer<f64>,
+    stats: Option<RollingStats>,
+    // Scratch space for `__getbuffer__`: `Py_buffer.shape`/`.strides` need
+    // a pointer that stays valid for the life of the exported view, so it
+    // can't point at a local. The pyclass itself is heap-allocated and
+    // stable, so these fields give it one.
+    buffer_shape: [isize; 1],
+    buffer_strides: [isize; 1],
 }
 
 #[pymethods]
@@ -145,19 +576,175 @@ impl RingBufferF64 {
     pub fn new(capacity: usize) -> PyResult<Self> {
         Ok(Self {
             inner: RingBuffer::new(capacity)?,
+            stats: None,
+            buffer_shape: [0],
+            buffer_strides: [0],
+        })
+    }
+
+    /// Builds a buffer with `min_capacity` rounded up to the next power of
+    /// two, so `push` can index the backing storage with a bitmask instead
+    /// of a modulo. Use this for hot tick-ingestion loops; `capacity`
+    /// reflects the rounded value, not `min_capacity`.
+    #[staticmethod]
+    pub fn new_pow2(min_capacity: usize) -> PyResult<Self> {
+        Ok(Self {
+            inner: RingBuffer::new_pow2(min_capacity)?,
+            stats: None,
+            buffer_shape: [0],
+            buffer_strides: [0],
+        })
+    }
+
+    /// Builds a buffer that also maintains rolling `mean`/`std`/`sum`/
+    /// `min`/`max` incrementally on every `push`/`extend`, recomputing
+    /// them exactly from a snapshot every `refresh_every` evictions to
+    /// bound accumulated float error (pass 0 to never auto-refresh; call
+    /// `refresh()` manually instead). `push_front`/`pop_front`/`pop_back`
+    /// would desync the incremental sums from an eviction they don't
+    /// know about, so they raise `ValueError` on a buffer built this way.
+    #[staticmethod]
+    pub fn with_stats(capacity: usize, refresh_every: usize) -> PyResult<Self> {
+        Ok(Self {
+            inner: RingBuffer::new(capacity)?,
+            stats: Some(RollingStats::new(refresh_every)),
+            buffer_shape: [0],
+            buffer_strides: [0],
         })
     }
 
     pub fn push(&mut self, value: f64) {
-        self.inner.push(value);
+        match self.stats.as_mut() {
+            Some(stats) => {
+                let evicted = if self.inner.is_full() {
+                    self.inner.front()
+                } else {
+                    None
+                };
+                self.inner.push(value);
+                stats.record_push(value, evicted, self.inner.capacity, self.inner.len());
+                if stats.needs_refresh() {
+                    stats.refresh(&self.inner.snapshot_vec());
+                }
+            }
+            None => self.inner.push(value),
+        }
     }
 
     pub fn extend(&mut self, values: Vec<f64>) {
-        self.inner.extend_from_slice(&values);
+        if self.stats.is_some() {
+            for value in values {
+                self.push(value);
+            }
+        } else {
+            self.inner.extend_from_slice(&values);
+        }
+    }
+
+    /// Forces an exact recompute of the rolling statistics from the
+    /// current window, discarding any accumulated float error. No-op if
+    /// the buffer was not constructed with `with_stats`.
+    pub fn refresh(&mut self) {
+        if let Some(stats) = self.stats.as_mut() {
+            stats.refresh(&self.inner.snapshot_vec());
+        }
+    }
+
+    fn stats_ref(&self) -> PyResult<&RollingStats> {
+        self.stats.as_ref().ok_or_else(|| {
+            PyValueError::new_err(
+                "rolling statistics are not enabled; construct with with_stats(...)",
+            )
+        })
+    }
+
+    /// Rejects an operation that cannot be folded into the incremental
+    /// rolling statistics (anything that evicts/inserts from the front or
+    /// the back without going through `push`/`extend`).
+    fn reject_if_stats_tracked(&self, op: &str) -> PyResult<()> {
+        if self.stats.is_some() {
+            return Err(PyValueError::new_err(format!(
+                "{op} is not supported on a buffer built with with_stats(...); it would \
+                 desync the incremental rolling statistics from the window"
+            )));
+        }
+        Ok(())
+    }
+
+    /// The rolling window mean. Errors if the buffer was not constructed
+    /// with `with_stats`.
+    pub fn mean(&self) -> PyResult<f64> {
+        Ok(self.stats_ref()?.mean(self.inner.len()))
+    }
+
+    /// The rolling window standard deviation. Errors if the buffer was
+    /// not constructed with `with_stats`.
+    pub fn std(&self) -> PyResult<f64> {
+        Ok(self.stats_ref()?.std(self.inner.len()))
+    }
+
+    /// The rolling window sum. Errors if the buffer was not constructed
+    /// with `with_stats`.
+    pub fn sum(&self) -> PyResult<f64> {
+        Ok(self.stats_ref()?.sum)
+    }
+
+    /// The rolling window minimum, or `None` if empty. Errors if the
+    /// buffer was not constructed with `with_stats`.
+    pub fn min(&self) -> PyResult<Option<f64>> {
+        Ok(self.stats_ref()?.min())
+    }
+
+    /// The rolling window maximum, or `None` if empty. Errors if the
+    /// buffer was not constructed with `with_stats`.
+    pub fn max(&self) -> PyResult<Option<f64>> {
+        Ok(self.stats_ref()?.max())
+    }
+
+    /// Inserts `value` at the front, dropping the newest element if the
+    /// buffer is already full. Errors if the buffer was built with
+    /// `with_stats(...)` — see `with_stats` for why.
+    pub fn push_front(&mut self, value: f64) -> PyResult<()> {
+        self.reject_if_stats_tracked("push_front")?;
+        self.inner.push_front(value);
+        Ok(())
+    }
+
+    /// Removes and returns the oldest element, or `None` if empty. Errors
+    /// if the buffer was built with `with_stats(...)` — see `with_stats`
+    /// for why.
+    pub fn pop_front(&mut self) -> PyResult<Option<f64>> {
+        self.reject_if_stats_tracked("pop_front")?;
+        Ok(self.inner.pop_front())
+    }
+
+    /// Removes and returns the newest element, or `None` if empty. Errors
+    /// if the buffer was built with `with_stats(...)` — see `with_stats`
+    /// for why.
+    pub fn pop_back(&mut self) -> PyResult<Option<f64>> {
+        self.reject_if_stats_tracked("pop_back")?;
+        Ok(self.inner.pop_back())
+    }
+
+    /// Peeks at the oldest element without removing it.
+    pub fn front(&self) -> Option<f64> {
+        self.inner.front()
+    }
+
+    /// Peeks at the newest element without removing it.
+    pub fn back(&self) -> Option<f64> {
+        self.inner.back()
     }
 
+    /// Clears the buffer. If the buffer was built with `with_stats(...)`,
+    /// also resets the rolling statistics (same `refresh_every`) so a
+    /// `mean()`/`min()`/`max()` call afterwards reflects the now-empty
+    /// window instead of stale pre-`clear()` accumulators.
     pub fn clear(&mut self) {
         self.inner.clear();
+        if let Some(stats) = self.stats.take() {
+            self.stats = Some(RollingStats::new(stats.refresh_every));
+        }
     }
 
     #[pyo3(name = "__len__")]
@@ -165,6 +752,9 @@ impl RingBufferF64 {
         self.inner.len()
     }
 
+    /// The buffer's storage capacity. When constructed via `new_pow2`, this
+    /// is `min_capacity` rounded up to the next power of two, not the
+    /// requested value.
     #[getter]
     pub fn capacity(&self) -> usize {
         self.inner.capacity
@@ -183,16 +773,292 @@ impl RingBufferF64 {
         self.inner.snapshot_vec()
     }
 
+    /// Gets the element at logical index `idx` in O(1). Index 0 is the
+    /// oldest element; negative indices count back from the newest
+    /// (`-1` is the newest), Python-style.
+    pub fn get(&self, idx: isize) -> PyResult<f64> {
+        self.inner.get(idx)
+    }
+
+    #[pyo3(name = "__getitem__")]
+    fn py_getitem(&self, idx: isize) -> PyResult<f64> {
+        self.inner.get(idx)
+    }
+
+    #[pyo3(name = "__setitem__")]
+    fn py_setitem(&mut self, idx: isize, value: f64) -> PyResult<()> {
+        self.inner.set(idx, value)
+    }
+
+    /// Returns the logical sub-range `[start, stop)` as a `Vec<f64>`
+    /// without copying the rest of the buffer.
+    pub fn slice(&self, start: isize, stop: isize) -> Vec<f64> {
+        self.inner.slice_vec(start, stop)
+    }
+
     #[allow(deprecated)]
     pub fn to_numpy(&self, py: Python<'_>) -> PyResult<Py<PyArray1<f64>>> {
         let snapshot = self.inner.snapshot_vec();
         Ok(PyArray1::from_vec(py, snapshot).to_owned())
     }
+
+    /// Exposes the raw physical storage through Python's buffer protocol
+    /// (`__getbuffer__` below) so NumPy can wrap it without copying, e.g.
+    /// `np.asarray(buf, copy=False)` or `memoryview(buf)`. This is the
+    /// fastest view, but it is NOT in logical (oldest-to-newest) order
+    /// once the buffer has wrapped — use `ordered_view()` for that, or
+    /// `snapshot()`/`to_numpy()` for an owned, always-ordered copy. The
+    /// view aliases `data` and is invalidated by the next `push`/
+    /// `extend`/`push_front`/`pop_front`/`pop_back`: do not hold it
+    /// across a mutating call. Read-only if the buffer was built with
+    /// `with_stats(...)`, since a write through the view would desync
+    /// the incremental rolling statistics the same way `push_front`/
+    /// `pop_front`/`pop_back` would.
+    pub fn view<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyMemoryView>> {
+        PyMemoryView::from(slf.as_any())
+    }
+
+    /// Like `view()` but in logical order: borrows the same memory as
+    /// `view()` when the buffer hasn't wrapped yet AND is full (`head ==
+    /// 0` and `len == capacity`, so the physical layout already matches
+    /// logical order with no trailing unused slots), and falls back to a
+    /// single owned, ordered copy otherwise, since one linear buffer view
+    /// can't express a wraparound or exclude the not-yet-written tail of
+    /// a partially filled buffer. Same invalidation contract as `view()`
+    /// when it returns a borrowed view.
+    #[allow(deprecated)]
+    pub fn ordered_view(slf: &Bound<'_, Self>) -> PyResult<PyObject> {
+        let unwrapped_and_full = {
+            let borrowed = slf.borrow();
+            borrowed.inner.head == 0 && borrowed.inner.is_full()
+        };
+        if unwrapped_and_full {
+            Ok(Self::view(slf)?.into_any().unbind())
+        } else {
+            let snapshot = slf.borrow().inner.snapshot_vec();
+            let array = PyArray1::from_vec(slf.py(), snapshot).to_owned();
+            Ok(array.into_any())
+        }
+    }
+
+    unsafe fn __getbuffer__(
+        mut slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("view is null"));
+        }
+
+        let len = slf.inner.data.len();
+        slf.buffer_shape = [len as isize];
+        slf.buffer_strides = [std::mem::size_of::<f64>() as isize];
+        let data_ptr = slf.inner.data.as_mut_ptr();
+
+        (*view).obj = ffi::_Py_NewRef(slf.as_ptr());
+        (*view).buf = data_ptr as *mut std::os::raw::c_void;
+        (*view).len = (len * std::mem::size_of::<f64>()) as isize;
+        // A buffer built with `with_stats(...)` maintains incremental
+        // sums/min/max alongside `data`; an in-place write through this
+        // view would desync them the same way `push_front`/`pop_front`/
+        // `pop_back` would, so export it read-only in that case instead
+        // of silently corrupting `mean()`/`std()`/`min()`/`max()`/`sum()`.
+        (*view).readonly = if slf.stats.is_some() { 1 } else { 0 };
+        (*view).itemsize = std::mem::size_of::<f64>() as isize;
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            CString::new("d").unwrap().into_raw()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).ndim = 1;
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            slf.buffer_shape.as_mut_ptr()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            slf.buffer_strides.as_mut_ptr()
+        } else {
+            ptr::null_mut()
+        };
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = ptr::null_mut();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(_slf: PyRefMut<'_, Self>, view: *mut ffi::Py_buffer) {
+        if !(*view).format.is_null() {
+            drop(CString::from_raw((*view).format));
+        }
+    }
 }
 
 #[pymodule]
 fn kapital_rust(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RingBufferI64>()?;
     m.add_class::<RingBufferF64>()?;
+    m.add_class::<RollingSMA>()?;
+    m.add_class::<RollingEMA>()?;
+    m.add_class::<RollingRSI>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_reset_on_clear() {
+        let mut buf = RingBufferF64::with_stats(3, 0).unwrap();
+        buf.push(10.0);
+        buf.push(20.0);
+        buf.push(30.0);
+        buf.clear();
+        buf.push(1.0);
+        buf.push(2.0);
+        buf.push(3.0);
+        assert_eq!(buf.mean().unwrap(), 2.0);
+        assert_eq!(buf.sum().unwrap(), 6.0);
+    }
+
+    #[test]
+    fn min_max_are_none_right_after_clear() {
+        let mut buf = RingBufferF64::with_stats(3, 0).unwrap();
+        buf.push(1.0);
+        buf.push(2.0);
+        buf.clear();
+        assert_eq!(buf.min().unwrap(), None);
+        assert_eq!(buf.max().unwrap(), None);
+        assert!(buf.mean().unwrap().is_nan());
+    }
+
+    #[test]
+    fn rolling_min_max_expire_as_the_window_slides() {
+        let mut buf = RingBufferF64::with_stats(3, 0).unwrap();
+        buf.push(5.0);
+        buf.push(1.0);
+        buf.push(4.0);
+        assert_eq!(buf.min().unwrap(), Some(1.0));
+        assert_eq!(buf.max().unwrap(), Some(5.0));
+        buf.push(9.0); // evicts 5.0 -> window [1, 4, 9]
+        assert_eq!(buf.min().unwrap(), Some(1.0));
+        assert_eq!(buf.max().unwrap(), Some(9.0));
+        buf.push(2.0); // evicts 1.0 -> window [4, 9, 2]
+        assert_eq!(buf.min().unwrap(), Some(2.0));
+        assert_eq!(buf.max().unwrap(), Some(9.0));
+    }
+
+    #[test]
+    fn refresh_every_recomputes_sum_exactly() {
+        let mut buf = RingBufferF64::with_stats(2, 2).unwrap();
+        buf.push(1.0);
+        buf.push(2.0);
+        buf.push(3.0); // 1st eviction
+        buf.push(4.0); // 2nd eviction -> auto-refresh of window [3, 4]
+        assert_eq!(buf.mean().unwrap(), 3.5);
+        assert_eq!(buf.sum().unwrap(), 7.0);
+    }
+
+    #[test]
+    fn deque_mutations_are_rejected_when_stats_are_tracked() {
+        let mut buf = RingBufferF64::with_stats(3, 0).unwrap();
+        buf.push(1.0);
+        assert!(buf.push_front(5.0).is_err());
+        assert!(buf.pop_front().is_err());
+        assert!(buf.pop_back().is_err());
+    }
+
+    #[test]
+    fn deque_mutations_are_allowed_without_stats() {
+        let mut buf = RingBufferF64::new(3).unwrap();
+        buf.push(1.0);
+        buf.push(2.0);
+        assert!(buf.push_front(0.0).is_ok());
+        assert_eq!(buf.pop_back().unwrap(), Some(2.0));
+        assert_eq!(buf.pop_front().unwrap(), Some(0.0));
+    }
+
+    #[test]
+    fn ordered_view_borrows_the_buffer_when_unwrapped_and_full() {
+        Python::with_gil(|py| {
+            let buf = Py::new(py, RingBufferI64::new(3).unwrap()).unwrap();
+            {
+                let mut inner = buf.borrow_mut(py);
+                inner.push(1);
+                inner.push(2);
+                inner.push(3);
+            }
+            assert_eq!(buf.borrow(py).inner.head, 0);
+            let bound = buf.bind(py);
+            let view = RingBufferI64::ordered_view(bound).unwrap();
+            let bound_view = view.bind(py);
+            assert!(bound_view.downcast::<PyMemoryView>().is_ok());
+            assert_eq!(bound_view.len().unwrap(), 3);
+        });
+    }
+
+    #[test]
+    fn ordered_view_copies_a_partially_filled_buffer_to_its_logical_length() {
+        Python::with_gil(|py| {
+            let buf = Py::new(py, RingBufferI64::new(5).unwrap()).unwrap();
+            {
+                let mut inner = buf.borrow_mut(py);
+                inner.push(1);
+                inner.push(2);
+            }
+            // head == 0 (nothing evicted yet) but len (2) < capacity (5):
+            // the view must not be the full-capacity physical storage.
+            assert_eq!(buf.borrow(py).inner.head, 0);
+            let bound = buf.bind(py);
+            let view = RingBufferI64::ordered_view(bound).unwrap();
+            let bound_view = view.bind(py);
+            assert!(bound_view.downcast::<PyArray1<i64>>().is_ok());
+            assert_eq!(bound_view.len().unwrap(), 2);
+        });
+    }
+
+    #[test]
+    fn ordered_view_falls_back_to_a_copy_once_wrapped() {
+        Python::with_gil(|py| {
+            let buf = Py::new(py, RingBufferI64::new(3).unwrap()).unwrap();
+            {
+                let mut inner = buf.borrow_mut(py);
+                inner.push(1);
+                inner.push(2);
+                inner.push(3);
+                inner.push(4); // evicts 1 -> head advances past 0
+            }
+            assert_ne!(buf.borrow(py).inner.head, 0);
+            let bound = buf.bind(py);
+            let view = RingBufferI64::ordered_view(bound).unwrap();
+            let bound_view = view.bind(py);
+            assert!(bound_view.downcast::<PyArray1<i64>>().is_ok());
+            assert_eq!(bound_view.len().unwrap(), 3);
+        });
+    }
+
+    #[test]
+    fn view_is_read_only_when_stats_are_tracked() {
+        Python::with_gil(|py| {
+            let buf = Py::new(py, RingBufferF64::with_stats(3, 0).unwrap()).unwrap();
+            buf.borrow_mut(py).push(1.0);
+            let bound = buf.bind(py);
+            let view = RingBufferF64::view(bound).unwrap();
+            let readonly: bool = view.getattr("readonly").unwrap().extract().unwrap();
+            assert!(readonly);
+        });
+    }
+
+    #[test]
+    fn view_is_writable_without_stats() {
+        Python::with_gil(|py| {
+            let buf = Py::new(py, RingBufferF64::new(3).unwrap()).unwrap();
+            buf.borrow_mut(py).push(1.0);
+            let bound = buf.bind(py);
+            let view = RingBufferF64::view(bound).unwrap();
+            let readonly: bool = view.getattr("readonly").unwrap().extract().unwrap();
+            assert!(!readonly);
+        });
+    }
+}